@@ -109,11 +109,15 @@ def _handle_global_install(args: argparse.Namespace, script_dir: Path) -> int:
     if args.uninstall:
         ok = uninstall_hooks(target_dir)
         if ok:
-            subprocess.run(["git", "config", "--global", "--unset", "core.hooksPath"], check=False)
+            subprocess.run(
+                ["git", "config", "--global", "--unset", "core.hooksPath"], check=False
+            )
         return 0 if ok else 1
     ok = install_hooks(target_dir, script_dir)
     if ok:
-        subprocess.run(["git", "config", "--global", "core.hooksPath", str(target_dir)], check=True)
+        subprocess.run(
+            ["git", "config", "--global", "core.hooksPath", str(target_dir)], check=True
+        )
     return 0 if ok else 1
 
 
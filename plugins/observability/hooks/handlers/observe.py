@@ -39,6 +39,7 @@ def _get_emitter(session_id: str | None = None):
 
 # === DISPATCH TABLE ===
 
+
 def _handle_session_start(emitter, event):
     emitter.session_started(
         source=event.get("matcher", "startup"),
@@ -1,2 +1 @@
 # Prompt validators package
-
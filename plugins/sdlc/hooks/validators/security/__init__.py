@@ -1,2 +1 @@
 # Security validators package
-
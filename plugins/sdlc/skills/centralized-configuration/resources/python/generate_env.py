@@ -299,7 +299,9 @@ def main() -> None:
 
     # Count variables
     total_vars = sum(
-        1 for line in content.split("\n") if "=" in line and not line.strip().startswith("#")
+        1
+        for line in content.split("\n")
+        if "=" in line and not line.strip().startswith("#")
     )
     print(f"   {total_vars} environment variables documented")
 
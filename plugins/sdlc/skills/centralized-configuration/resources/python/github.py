@@ -102,7 +102,9 @@ class GitHubSettings(BaseSettings):
 
     webhook_secret: SecretStr | None = Field(
         default=None,
-        description=("HMAC secret for webhook verification. Generate with: openssl rand -hex 32"),
+        description=(
+            "HMAC secret for webhook verification. Generate with: openssl rand -hex 32"
+        ),
     )
 
     # =========================================================================
@@ -101,7 +101,9 @@ class Settings(BaseSettings):
 
     api_key: SecretStr | None = Field(
         default=None,
-        description=("API key for external service. Get from: https://example.com/keys"),
+        description=(
+            "API key for external service. Get from: https://example.com/keys"
+        ),
     )
 
     # =========================================================================
@@ -130,7 +130,9 @@ def settings_section(
         if desc:
             lines.extend(_comment_lines(desc))
 
-        lines.append(f"{env_name}=" if _is_secret(field_type) else f"{env_name}={default}")
+        lines.append(
+            f"{env_name}=" if _is_secret(field_type) else f"{env_name}={default}"
+        )
         lines.append("")
 
     return lines